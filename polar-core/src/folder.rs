@@ -5,11 +5,9 @@
 //! Paraphrasing the above, a Folder represents an AST->AST fold; it consumes an AST and returns an
 //! AST of the same type.
 
-// TODO(gj): Consider transitioning to a MutVisitor like
-//           https://docs.rs/rustc-ap-syntax/645.0.0/src/rustc_ap_syntax/mut_visit.rs.html
-
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
+use crate::kb::KnowledgeBase;
 use crate::rules::*;
 use crate::terms::*;
 
@@ -73,6 +71,29 @@ pub trait Folder: Sized {
     fn fold_param(&mut self, p: Parameter) -> Parameter {
         fold_param(p, self)
     }
+    fn fold_term_list(&mut self, t: Vec<Term>) -> Vec<Term> {
+        fold_term_list(t, self)
+    }
+    fn fold_fields(&mut self, f: BTreeMap<Symbol, Term>) -> BTreeMap<Symbol, Term> {
+        fold_fields(f, self)
+    }
+    fn fold_kwargs(
+        &mut self,
+        k: Option<BTreeMap<Symbol, Term>>,
+    ) -> Option<BTreeMap<Symbol, Term>> {
+        fold_kwargs(k, self)
+    }
+
+    /// `Chain` only composes the six leaf methods above (`fold_number`, `fold_string`,
+    /// `fold_boolean`, `fold_symbol`, `fold_variable`, `fold_operator`); every structural method
+    /// (`fold_call`, `fold_list`, `fold_dictionary`, `fold_operation`, `fold_rule`, ...) is left
+    /// at its trait default when a folder is composed with `Chain`, so an override of one of
+    /// those would otherwise be silently dropped with no compile error. A `Folder` impl that
+    /// overrides any structural method MUST override this to return `true`, so `Chain::new` and
+    /// `fold_all` can refuse to compose it instead of silently ignoring its override.
+    fn overrides_structural_methods(&self) -> bool {
+        false
+    }
 }
 
 pub fn fold_rule<T: Folder>(
@@ -107,6 +128,23 @@ pub fn fold_term_list<T: Folder>(mut t: Vec<Term>, fld: &mut T) -> Vec<Term> {
     t
 }
 
+pub fn fold_fields<T: Folder>(
+    fields: BTreeMap<Symbol, Term>,
+    fld: &mut T,
+) -> BTreeMap<Symbol, Term> {
+    fields
+        .into_iter()
+        .map(|(k, v)| (fld.fold_symbol(k), fld.fold_term(v)))
+        .collect()
+}
+
+pub fn fold_kwargs<T: Folder>(
+    kwargs: Option<BTreeMap<Symbol, Term>>,
+    fld: &mut T,
+) -> Option<BTreeMap<Symbol, Term>> {
+    kwargs.map(|kwargs| fld.fold_fields(kwargs))
+}
+
 pub fn fold_value<T: Folder>(v: Value, fld: &mut T) -> Value {
     match v {
         Value::Number(n) => Value::Number(fld.fold_number(n)),
@@ -145,23 +183,15 @@ pub fn fold_instance_literal<T: Folder>(
 
 pub fn fold_dictionary<T: Folder>(Dictionary { fields }: Dictionary, fld: &mut T) -> Dictionary {
     Dictionary {
-        fields: fields
-            .into_iter()
-            .map(|(k, v)| (fld.fold_symbol(k), fld.fold_term(v)))
-            .collect::<BTreeMap<Symbol, Term>>(),
+        fields: fld.fold_fields(fields),
     }
 }
 
 pub fn fold_call<T: Folder>(Call { name, args, kwargs }: Call, fld: &mut T) -> Call {
     Call {
         name: fld.fold_symbol(name),
-        args: fold_term_list(args, fld),
-        kwargs: kwargs.map(|kwargs| {
-            kwargs
-                .into_iter()
-                .map(|(k, v)| (fld.fold_symbol(k), fld.fold_term(v)))
-                .collect::<BTreeMap<Symbol, Term>>()
-        }),
+        args: fld.fold_term_list(args),
+        kwargs: fld.fold_kwargs(kwargs),
     }
 }
 
@@ -171,7 +201,7 @@ pub fn fold_variable<T: Folder>(v: Variable, _fld: &mut T) -> Variable {
 
 pub fn fold_list<T: Folder>(l: List, fld: &mut T) -> List {
     List {
-        elements: fold_term_list(l.elements, fld),
+        elements: fld.fold_term_list(l.elements),
         rest_var: l.rest_var.map(|rv| fld.fold_variable(rv)),
     }
 }
@@ -186,7 +216,7 @@ pub fn fold_operation<T: Folder>(
 ) -> Operation {
     Operation {
         operator: fld.fold_operator(operator),
-        args: fold_term_list(args, fld),
+        args: fld.fold_term_list(args),
     }
 }
 
@@ -207,6 +237,404 @@ pub fn fold_param<T: Folder>(
     }
 }
 
+/// Fuses two single-purpose folders into one descent, so the AST is only walked once even
+/// though both folders' rewrites apply at every node. `Chain::new(a, b)` does this by composing
+/// only the leaf-level methods (`fold_number`, `fold_string`, `fold_boolean`, `fold_symbol`,
+/// `fold_variable`, `fold_operator`) — `a` first, then `b` on `a`'s output — and leaving every
+/// structural method (`fold_term`, `fold_value`, `fold_call`, `fold_list`, ...) at its trait
+/// default, so the default recursion drives exactly one traversal through `self` and reaches
+/// `Chain`'s composed leaf methods exactly once per node, rather than `a` and `b` each recursing
+/// through the whole subtree on their own.
+///
+/// Modeled on the pass-combinator approach used in JS transform pipelines, where several
+/// single-purpose folds (ones that only rewrite leaf values and otherwise recurse normally) are
+/// fused into one `Fold` applied in a single descent, rather than each pass re-walking the whole
+/// tree on its own. A folder that overrides a structural method directly (not just a leaf
+/// method) can't be fused by `Chain` — its override would be silently skipped instead — so
+/// `Chain::new` panics if either side reports (via `Folder::overrides_structural_methods`) that
+/// it overrides one, rather than composing it wrong with no error at all.
+pub struct Chain<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Folder, B: Folder> Chain<A, B> {
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` overrides a structural `Folder` method (see
+    /// `Folder::overrides_structural_methods`), since `Chain` only composes the six leaf methods
+    /// and would otherwise silently drop that override.
+    pub fn new(a: A, b: B) -> Self {
+        assert!(
+            !a.overrides_structural_methods() && !b.overrides_structural_methods(),
+            "Chain only composes Folder's leaf methods (fold_number, fold_string, fold_boolean, \
+             fold_symbol, fold_variable, fold_operator); a folder overriding a structural method \
+             (fold_call, fold_list, fold_dictionary, fold_operation, ...) can't be composed with \
+             Chain, since that override would be silently skipped instead of applied"
+        );
+        Self { a, b }
+    }
+}
+
+impl<A: Folder, B: Folder> Folder for Chain<A, B> {
+    fn fold_number(&mut self, n: Numeric) -> Numeric {
+        self.b.fold_number(self.a.fold_number(n))
+    }
+    fn fold_string(&mut self, s: String) -> String {
+        self.b.fold_string(self.a.fold_string(s))
+    }
+    fn fold_boolean(&mut self, b: bool) -> bool {
+        self.b.fold_boolean(self.a.fold_boolean(b))
+    }
+    fn fold_symbol(&mut self, n: Symbol) -> Symbol {
+        self.b.fold_symbol(self.a.fold_symbol(n))
+    }
+    fn fold_variable(&mut self, v: Variable) -> Variable {
+        self.b.fold_variable(self.a.fold_variable(v))
+    }
+    fn fold_operator(&mut self, o: Operator) -> Operator {
+        self.b.fold_operator(self.a.fold_operator(o))
+    }
+    // Every structural method (fold_rule, fold_term, fold_value, fold_call, fold_list, ...) is
+    // left at the trait default, so it recurses through `self` — this `Chain` — and lands back
+    // on the leaf methods above exactly once per node. Overriding them here to call
+    // `self.b.fold_x(self.a.fold_x(x))` would make `a` and `b` each run their own full recursive
+    // traversal of the subtree, walking it twice instead of fusing into one descent.
+}
+
+impl Folder for Box<dyn Folder> {
+    fn fold_number(&mut self, n: Numeric) -> Numeric {
+        (**self).fold_number(n)
+    }
+    fn fold_string(&mut self, s: String) -> String {
+        (**self).fold_string(s)
+    }
+    fn fold_boolean(&mut self, b: bool) -> bool {
+        (**self).fold_boolean(b)
+    }
+    fn fold_symbol(&mut self, n: Symbol) -> Symbol {
+        (**self).fold_symbol(n)
+    }
+    fn fold_variable(&mut self, v: Variable) -> Variable {
+        (**self).fold_variable(v)
+    }
+    fn fold_operator(&mut self, o: Operator) -> Operator {
+        (**self).fold_operator(o)
+    }
+    fn fold_rule(&mut self, r: Rule) -> Rule {
+        (**self).fold_rule(r)
+    }
+    fn fold_term(&mut self, t: Term) -> Term {
+        (**self).fold_term(t)
+    }
+    fn fold_value(&mut self, v: Value) -> Value {
+        (**self).fold_value(v)
+    }
+    fn fold_instance_literal(&mut self, i: InstanceLiteral) -> InstanceLiteral {
+        (**self).fold_instance_literal(i)
+    }
+    fn fold_dictionary(&mut self, d: Dictionary) -> Dictionary {
+        (**self).fold_dictionary(d)
+    }
+    fn fold_call(&mut self, c: Call) -> Call {
+        (**self).fold_call(c)
+    }
+    fn fold_list(&mut self, l: List) -> List {
+        (**self).fold_list(l)
+    }
+    fn fold_operation(&mut self, o: Operation) -> Operation {
+        (**self).fold_operation(o)
+    }
+    fn fold_param(&mut self, p: Parameter) -> Parameter {
+        (**self).fold_param(p)
+    }
+    fn fold_term_list(&mut self, t: Vec<Term>) -> Vec<Term> {
+        (**self).fold_term_list(t)
+    }
+    fn fold_fields(&mut self, f: BTreeMap<Symbol, Term>) -> BTreeMap<Symbol, Term> {
+        (**self).fold_fields(f)
+    }
+    fn fold_kwargs(
+        &mut self,
+        k: Option<BTreeMap<Symbol, Term>>,
+    ) -> Option<BTreeMap<Symbol, Term>> {
+        (**self).fold_kwargs(k)
+    }
+    fn overrides_structural_methods(&self) -> bool {
+        (**self).overrides_structural_methods()
+    }
+}
+
+/// Fuses a list of folders into a single folder that walks the AST once, running each node
+/// through every folder in order (`folders[0]` first, `folders[n-1]` last), rather than running
+/// each folder as its own full traversal over the knowledge base.
+///
+/// Returns a no-op folder if `folders` is empty.
+///
+/// # Panics
+///
+/// Panics (via `Chain::new`) if any folder in `folders` overrides a structural `Folder` method;
+/// see `Folder::overrides_structural_methods`.
+pub fn fold_all(folders: Vec<Box<dyn Folder>>) -> Box<dyn Folder> {
+    struct Identity;
+    impl Folder for Identity {}
+
+    folders
+        .into_iter()
+        .reduce(|a, b| Box::new(Chain::new(a, b)) as Box<dyn Folder>)
+        .unwrap_or_else(|| Box::new(Identity) as Box<dyn Folder>)
+}
+
+/// Renames every variable in a rule to a fresh symbol generated from `KnowledgeBase::gensym`,
+/// so that variables from different applications of the same rule never accidentally unify.
+///
+/// This mirrors the node-ID-assigning fold pattern from rustc's front end, where a fold exists
+/// purely to hand out fresh identifiers. The same original variable always renames to the same
+/// fresh symbol within one `fold_rule` call, except wildcards (`_`), which each get their own
+/// distinct fresh name.
+pub struct Renamer<'kb> {
+    kb: &'kb KnowledgeBase,
+    renames: HashMap<Symbol, Symbol>,
+}
+
+impl<'kb> Renamer<'kb> {
+    pub fn new(kb: &'kb KnowledgeBase) -> Self {
+        Self {
+            kb,
+            renames: HashMap::new(),
+        }
+    }
+}
+
+impl<'kb> Folder for Renamer<'kb> {
+    fn fold_variable(&mut self, v: Variable) -> Variable {
+        if v.0 == "_" {
+            return self.kb.gensym("_");
+        }
+        let kb = self.kb;
+        self.renames
+            .entry(v.clone())
+            .or_insert_with(|| kb.gensym(&v.0))
+            .clone()
+    }
+}
+
+/// Paraphrasing https://docs.rs/rustc-ap-syntax/645.0.0/src/rustc_ap_syntax/visit.rs.html:
+///
+/// A Visitor is the read-only counterpart to `Folder`: it inspects `&T` substructure without
+/// consuming or rebuilding it, for passes (collecting variables, gathering referenced classes,
+/// counting operations) that never need to produce a new AST. Each method is a hook to be
+/// potentially overridden; each default implementation recursively visits the substructure of
+/// the input via the corresponding `walk_*` function.
+pub trait Visitor: Sized {
+    fn visit_rule(&mut self, r: &Rule) {
+        walk_rule(r, self)
+    }
+    fn visit_term(&mut self, t: &Term) {
+        walk_term(t, self)
+    }
+    fn visit_value(&mut self, v: &Value) {
+        walk_value(v, self)
+    }
+    fn visit_dictionary(&mut self, d: &Dictionary) {
+        walk_dictionary(d, self)
+    }
+    fn visit_call(&mut self, c: &Call) {
+        walk_call(c, self)
+    }
+    fn visit_list(&mut self, l: &List) {
+        walk_list(l, self)
+    }
+    fn visit_operation(&mut self, o: &Operation) {
+        walk_operation(o, self)
+    }
+    fn visit_param(&mut self, p: &Parameter) {
+        walk_param(p, self)
+    }
+    fn visit_symbol(&mut self, _s: &Symbol) {}
+    fn visit_variable(&mut self, _v: &Variable) {}
+}
+
+pub fn walk_rule<T: Visitor>(r: &Rule, visitor: &mut T) {
+    for param in &r.params {
+        visitor.visit_param(param);
+    }
+    visitor.visit_term(&r.body);
+}
+
+pub fn walk_term<T: Visitor>(t: &Term, visitor: &mut T) {
+    visitor.visit_value(t.value());
+}
+
+pub fn walk_value<T: Visitor>(v: &Value, visitor: &mut T) {
+    match v {
+        Value::Number(_) | Value::String(_) | Value::Boolean(_) => (),
+        Value::InstanceLiteral(i) => {
+            visitor.visit_symbol(&i.tag);
+            visitor.visit_dictionary(&i.fields);
+        }
+        Value::Dictionary(d) => visitor.visit_dictionary(d),
+        Value::Call(c) => visitor.visit_call(c),
+        Value::List(l) => visitor.visit_list(l),
+        Value::Variable(v) => visitor.visit_variable(v),
+        Value::Expression(o) => visitor.visit_operation(o),
+    }
+}
+
+pub fn walk_dictionary<T: Visitor>(d: &Dictionary, visitor: &mut T) {
+    for (k, v) in &d.fields {
+        visitor.visit_symbol(k);
+        visitor.visit_term(v);
+    }
+}
+
+pub fn walk_call<T: Visitor>(c: &Call, visitor: &mut T) {
+    visitor.visit_symbol(&c.name);
+    for arg in &c.args {
+        visitor.visit_term(arg);
+    }
+    if let Some(kwargs) = &c.kwargs {
+        for (k, v) in kwargs {
+            visitor.visit_symbol(k);
+            visitor.visit_term(v);
+        }
+    }
+}
+
+pub fn walk_list<T: Visitor>(l: &List, visitor: &mut T) {
+    for elem in &l.elements {
+        visitor.visit_term(elem);
+    }
+    if let Some(rest_var) = &l.rest_var {
+        visitor.visit_variable(rest_var);
+    }
+}
+
+pub fn walk_operation<T: Visitor>(o: &Operation, visitor: &mut T) {
+    for arg in &o.args {
+        visitor.visit_term(arg);
+    }
+}
+
+pub fn walk_param<T: Visitor>(p: &Parameter, visitor: &mut T) {
+    visitor.visit_term(&p.parameter);
+    if let Some(specializer) = &p.specializer {
+        visitor.visit_term(specializer);
+    }
+}
+
+/// Paraphrasing https://docs.rs/rustc-ap-syntax/645.0.0/src/rustc_ap_syntax/mut_visit.rs.html:
+///
+/// A MutVisitor represents an AST->AST fold that mutates its input in place instead of
+/// consuming and rebuilding it. Each method is a hook to be potentially overridden; each
+/// default implementation recursively visits the substructure of the input via the
+/// corresponding `walk_mut_*` function.
+///
+/// As implemented today, this buys less than it looks like it should. `walk_mut_dictionary` and
+/// `walk_mut_call` mutate `Dictionary` fields and `Call` kwargs through `values_mut()` without
+/// rebuilding the `BTreeMap`, which is a real win over `Folder::fold_fields` (also reached via
+/// `Folder::fold_kwargs`), whose `.collect()` allocates a fresh map. But
+/// `walk_mut_list`/`walk_mut_call`/`walk_mut_operation` just iterate a `Vec<Term>` via
+/// `iter_mut()` — and `Folder::fold_term_list` (unchanged by this module) already mutates its
+/// `Vec` the same way via `mem::swap` per element, so there's no allocation delta between
+/// `Folder` and `MutVisitor` for `List` elements or `Call` args.
+///
+/// Worse, `walk_mut_term` — the node every one of the above bottoms out at — still clones: `Term`
+/// only exposes `value()` (`&Value`), not a mutable accessor, so mutating the `Value` inside a
+/// `Term` requires cloning it out and reassembling the `Term` via `clone_with_value`, the same
+/// allocate-and-rebuild `Folder::fold_term` does. So `MutVisitor` has the same allocation cost
+/// as `Folder` everywhere except `Dictionary` fields and `Call` kwargs; it does not avoid
+/// cloning the `Value` inside each `Term` it visits.
+pub trait MutVisitor: Sized {
+    fn visit_mut_rule(&mut self, r: &mut Rule) {
+        walk_mut_rule(r, self)
+    }
+    fn visit_mut_term(&mut self, t: &mut Term) {
+        walk_mut_term(t, self)
+    }
+    fn visit_mut_value(&mut self, v: &mut Value) {
+        walk_mut_value(v, self)
+    }
+    fn visit_mut_call(&mut self, c: &mut Call) {
+        walk_mut_call(c, self)
+    }
+    fn visit_mut_dictionary(&mut self, d: &mut Dictionary) {
+        walk_mut_dictionary(d, self)
+    }
+    fn visit_mut_list(&mut self, l: &mut List) {
+        walk_mut_list(l, self)
+    }
+    fn visit_mut_operation(&mut self, o: &mut Operation) {
+        walk_mut_operation(o, self)
+    }
+    fn visit_mut_param(&mut self, p: &mut Parameter) {
+        walk_mut_param(p, self)
+    }
+}
+
+pub fn walk_mut_rule<V: MutVisitor>(r: &mut Rule, visitor: &mut V) {
+    for param in r.params.iter_mut() {
+        visitor.visit_mut_param(param);
+    }
+    visitor.visit_mut_term(&mut r.body);
+}
+
+pub fn walk_mut_term<V: MutVisitor>(t: &mut Term, visitor: &mut V) {
+    // `Term` has no `value_mut`, so this still has to clone the `Value` out and rebuild the
+    // `Term` around the mutated copy; see the `MutVisitor` doc comment above.
+    let mut owned = std::mem::replace(t, term!(false));
+    let mut value = owned.value().clone();
+    visitor.visit_mut_value(&mut value);
+    *t = owned.clone_with_value(value);
+}
+
+pub fn walk_mut_value<V: MutVisitor>(v: &mut Value, visitor: &mut V) {
+    match v {
+        Value::Number(_) | Value::String(_) | Value::Boolean(_) | Value::Variable(_) => (),
+        Value::InstanceLiteral(i) => visitor.visit_mut_dictionary(&mut i.fields),
+        Value::Dictionary(d) => visitor.visit_mut_dictionary(d),
+        Value::Call(c) => visitor.visit_mut_call(c),
+        Value::List(l) => visitor.visit_mut_list(l),
+        Value::Expression(o) => visitor.visit_mut_operation(o),
+    }
+}
+
+pub fn walk_mut_call<V: MutVisitor>(c: &mut Call, visitor: &mut V) {
+    for arg in c.args.iter_mut() {
+        visitor.visit_mut_term(arg);
+    }
+    if let Some(kwargs) = c.kwargs.as_mut() {
+        for v in kwargs.values_mut() {
+            visitor.visit_mut_term(v);
+        }
+    }
+}
+
+pub fn walk_mut_dictionary<V: MutVisitor>(d: &mut Dictionary, visitor: &mut V) {
+    for v in d.fields.values_mut() {
+        visitor.visit_mut_term(v);
+    }
+}
+
+pub fn walk_mut_list<V: MutVisitor>(l: &mut List, visitor: &mut V) {
+    for elem in l.elements.iter_mut() {
+        visitor.visit_mut_term(elem);
+    }
+}
+
+pub fn walk_mut_operation<V: MutVisitor>(o: &mut Operation, visitor: &mut V) {
+    for arg in o.args.iter_mut() {
+        visitor.visit_mut_term(arg);
+    }
+}
+
+pub fn walk_mut_param<V: MutVisitor>(p: &mut Parameter, visitor: &mut V) {
+    visitor.visit_mut_term(&mut p.parameter);
+    if let Some(specializer) = p.specializer.as_mut() {
+        visitor.visit_mut_term(specializer);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,4 +693,187 @@ mod tests {
         let mut fld = TrivialFolder {};
         assert_eq!(fld.fold_rule(rule.clone()), rule);
     }
+
+    #[derive(Default)]
+    struct VariableCollector {
+        variables: Vec<Variable>,
+    }
+
+    impl Visitor for VariableCollector {
+        fn visit_variable(&mut self, v: &Variable) {
+            self.variables.push(v.clone());
+        }
+    }
+
+    #[test]
+    fn test_visit_collects_variables() {
+        let rule = rule!("a", ["b"; instance!("c"), value!(sym!("x"))] => call!("e", [value!(sym!("y"))]));
+        let mut collector = VariableCollector::default();
+        collector.visit_rule(&rule);
+        assert_eq!(collector.variables, vec![sym!("x"), sym!("y")]);
+    }
+
+    #[test]
+    fn test_rename_rule_is_consistent_per_variable_and_distinct_per_wildcard() {
+        let rule = rule!("a", ["b"; value!("d")] => call!(
+            "f",
+            [
+                value!(sym!("x")),
+                value!(sym!("_")),
+                value!(sym!("x")),
+                value!(sym!("_"))
+            ]
+        ));
+        let kb = KnowledgeBase::new();
+        let renamed = kb.rename_rule(rule);
+
+        let mut collector = VariableCollector::default();
+        collector.visit_rule(&renamed);
+        let vars = collector.variables;
+        assert_eq!(vars.len(), 4);
+
+        // Repeated uses of `x` rename consistently...
+        assert_eq!(vars[0], vars[2]);
+        // ...while each `_` gets its own distinct fresh name...
+        assert_ne!(vars[1], vars[3]);
+        // ...and the fresh names neither reuse the original symbols nor collide with each other.
+        assert_ne!(vars[0], sym!("x"));
+        assert_ne!(vars[1], sym!("_"));
+        assert_ne!(vars[0], vars[1]);
+    }
+
+    struct BooleanFlipper {}
+    impl Folder for BooleanFlipper {
+        fn fold_boolean(&mut self, b: bool) -> bool {
+            !b
+        }
+    }
+
+    struct StringShouter {}
+    impl Folder for StringShouter {
+        fn fold_string(&mut self, s: String) -> String {
+            s.to_uppercase()
+        }
+    }
+
+    #[test]
+    fn test_chain_composes_both_folders_in_one_pass() {
+        let term = term!(vec![term!(value!(true)), term!(value!("hi"))]);
+        let mut chained = Chain::new(BooleanFlipper {}, StringShouter {});
+        let folded = chained.fold_term(term);
+        assert_eq!(
+            folded,
+            term!(vec![term!(value!(false)), term!(value!("HI"))])
+        );
+    }
+
+    #[test]
+    fn test_fold_all_fuses_a_list_of_folders() {
+        let term = term!(vec![term!(value!(true)), term!(value!("hi"))]);
+        let folders: Vec<Box<dyn Folder>> =
+            vec![Box::new(BooleanFlipper {}), Box::new(StringShouter {})];
+        let mut fused = fold_all(folders);
+        let folded = fused.fold_term(term);
+        assert_eq!(
+            folded,
+            term!(vec![term!(value!(false)), term!(value!("HI"))])
+        );
+    }
+
+    struct ListOverrider {}
+    impl Folder for ListOverrider {
+        fn fold_list(&mut self, l: List) -> List {
+            fold_list(l, self)
+        }
+        fn overrides_structural_methods(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "overriding a structural method")]
+    fn test_chain_new_panics_on_a_folder_overriding_a_structural_method() {
+        Chain::new(ListOverrider {}, BooleanFlipper {});
+    }
+
+    #[test]
+    #[should_panic(expected = "overriding a structural method")]
+    fn test_fold_all_panics_on_a_folder_overriding_a_structural_method() {
+        let folders: Vec<Box<dyn Folder>> =
+            vec![Box::new(BooleanFlipper {}), Box::new(ListOverrider {})];
+        fold_all(folders);
+    }
+
+    struct TaggedFolder {
+        tag: &'static str,
+        log: std::rc::Rc<std::cell::RefCell<Vec<&'static str>>>,
+    }
+    impl Folder for TaggedFolder {
+        fn fold_boolean(&mut self, b: bool) -> bool {
+            self.log.borrow_mut().push(self.tag);
+            b
+        }
+        fn fold_string(&mut self, s: String) -> String {
+            self.log.borrow_mut().push(self.tag);
+            s
+        }
+    }
+
+    #[test]
+    fn test_chain_interleaves_folders_per_node_instead_of_running_two_full_passes() {
+        // Two leaf values at the top level of one list: if `Chain` truly fuses into a single
+        // descent, `a` and `b` alternate per node (a, b, a, b). If it instead ran `a`'s full
+        // traversal to completion before starting `b`'s (two sequential full passes), the log
+        // would read (a, a, b, b) instead.
+        let term = term!(vec![term!(value!(true)), term!(value!("hi"))]);
+        let log = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let mut chained = Chain::new(
+            TaggedFolder {
+                tag: "a",
+                log: log.clone(),
+            },
+            TaggedFolder {
+                tag: "b",
+                log: log.clone(),
+            },
+        );
+        chained.fold_term(term);
+        assert_eq!(*log.borrow(), vec!["a", "b", "a", "b"]);
+    }
+
+    #[derive(Default)]
+    struct ListCounter {
+        lists_visited: usize,
+    }
+    impl Folder for ListCounter {
+        fn fold_list(&mut self, l: List) -> List {
+            self.lists_visited += 1;
+            fold_list(l, self)
+        }
+    }
+
+    #[test]
+    fn test_fold_list_hook_fires_for_every_list_including_nested_call_args() {
+        // Lists nested inside a call's args are reached through `fold_call` -> `fold_term_list`
+        // -> `fold_term` -> `fold_value`, so an overridden `fold_list` must fire once for each of
+        // them.
+        let nested_list = term!(vec![term!(value!(1)), term!(value!(2))]);
+        let other_list = term!(vec![term!(value!(3))]);
+        let term = term!(value!(call!("f", [nested_list, other_list])));
+        let mut counter = ListCounter::default();
+        counter.fold_term(term);
+        assert_eq!(counter.lists_visited, 2);
+    }
+
+    struct TrivialVisitor {}
+    impl MutVisitor for TrivialVisitor {}
+
+    #[test]
+    fn test_visit_mut_rule() {
+        let rule = rule!("a", ["b"; instance!("c"), value!("d")] => call!("e", [value!("f")]));
+        let mut mutated = rule.clone();
+        let mut visitor = TrivialVisitor {};
+        visitor.visit_mut_rule(&mut mutated);
+        assert_eq!(mutated, rule);
+    }
 }
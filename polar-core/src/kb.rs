@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use super::counter::Counter;
+use super::folder::{Folder, Renamer};
 use super::rules::*;
 use super::sources::*;
 use super::terms::*;
@@ -152,6 +153,12 @@ impl KnowledgeBase {
         generic_rule.add_rule(Arc::new(rule));
     }
 
+    /// Rename every variable in `rule` to a fresh symbol, so the returned copy can be applied
+    /// without its variables unifying with those of another application of the same rule.
+    pub fn rename_rule(&self, rule: Rule) -> Rule {
+        Renamer::new(self).fold_rule(rule)
+    }
+
     /// Clear rules from KB, leaving constants in place.
     pub fn clear_rules(&mut self) {
         for scope in self.scopes.iter_mut() {